@@ -0,0 +1,94 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// Error produced when a token the parser is about to build does not match the pattern its
+/// [SyntaxKind](crate::syntax_kind::SyntaxKind) requires — an identifier containing punctuation,
+/// for instance.
+#[derive(Debug, Eq, PartialEq)]
+pub struct UnmatchedPatternError {
+  /// The message to be displayed that describes the error.
+  message: String,
+
+  /// Byte range, in the source, at which the match was attempted and failed.
+  span: Range<usize>,
+
+  /// Human-readable description of the pattern that `found` was expected to match.
+  expected: String,
+
+  /// Text that was found at `span` instead of something matching `expected`.
+  found: String
+}
+
+impl UnmatchedPatternError {
+  /// Instantiates an error for a failure to match `found` against `expected` at `span`.
+  pub(crate) fn new(message: String, span: Range<usize>, expected: String, found: String) -> Self {
+    UnmatchedPatternError {
+      message,
+      span,
+      expected,
+      found
+    }
+  }
+
+  /// Renders this error as a multi-line, caret-underlined diagnostic against the original `source`,
+  /// in the style popularized by miette: a primary label at the failing span, the text that was
+  /// found there, and a short help line naming what was expected instead.
+  pub fn report(&self, source: &str) -> String {
+    let line_start = source[..self.span.start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = source[self.span.start..].find('\n').map_or(source.len(), |index| self.span.start + index);
+    let line = &source[line_start..line_end];
+    let line_number = source[..line_start].matches('\n').count() + 1;
+    let column = self.span.start - line_start;
+    let underline_len = (self.span.end - self.span.start).max(1);
+    format!(
+      "error: {message}\n  --> line {line_number}, column {column}\n   |\n{line_number:>3}| {line}\n   | {caret:>column$}{underline}\n   = help: expected {expected}, found {found:?}",
+      message = self.message,
+      line_number = line_number,
+      column = column,
+      line = line,
+      caret = "",
+      underline = "^".repeat(underline_len),
+      expected = self.expected,
+      found = self.found
+    )
+  }
+}
+
+impl Display for UnmatchedPatternError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    Display::fmt(&self.message, f)
+  }
+}
+
+impl std::error::Error for UnmatchedPatternError {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reports_a_caret_underlined_diagnostic() {
+    let error = UnmatchedPatternError::new(
+      String::from("`1!` is not a valid identifier"),
+      5..7,
+      String::from("identifier matching `^[a-zA-Z0-9]+$`"),
+      String::from("1!")
+    );
+    let report = error.report("func 1!()");
+    assert!(report.contains("line 1, column 5"));
+    assert!(report.contains("^^"));
+    assert!(report.contains("expected identifier matching `^[a-zA-Z0-9]+$`, found \"1!\""));
+  }
+}
@@ -10,134 +10,281 @@
 // or implied. See the License for the specific language governing permissions and limitations under
 // the License.
 
+use std::sync::Arc;
+
 use regex::Regex;
 
-use crate::node::{IdentifierNode, ListSeparatorNode, Node, OperationNode, SpacingNode};
+use crate::error::UnmatchedPatternError;
+use crate::green::GreenNode;
+use crate::node_cache::{GreenNodeBuilder, NodeCache};
+use crate::syntax_kind::SyntaxKind;
+
+/// Pattern every `Identifier` token — a function's own name, or a value parameter's name — must
+/// match. Unlike a value parameter's `type_name`, which may be a composite type expression such as
+/// `string[]`, an identifier is always a bare run of letters and/or digits.
+const IDENTIFIER_PATTERN: &str = r"^[a-zA-Z0-9]+$";
 
-/// Node that denotes the start of a scope of a function whose declaration is confined to a single
-/// line.
+/// A single `type_name identifier` pair declared within a function's value parameter list.
 #[derive(Debug)]
-struct SinglyLinedFunctionScopeDelimiterNode {
-  /// Vertical position of this node in the AST. Corresponds to the number of the line in which it
-  /// is located in the source file.
-  column: u32,
-
-  /// Horizontal position of this node in the AST. Corresponds to the index of the character that
-  /// delimits the start of this node in the line of the source file in which it is located.
-  row: u32
+pub(crate) struct ValueParameter<'a> {
+  /// Name of the type of the value as it was input by the user: may or may not be qualified.
+  pub(crate) type_name: &'a str,
+
+  /// Name attributed to this value parameter.
+  pub(crate) identifier: &'a str
 }
 
-impl Node for SinglyLinedFunctionScopeDelimiterNode {
-  fn pattern() -> Regex {
-    Regex::new(r":").unwrap()
+/// Builds the green tree of a declaration of a function named `identifier`, receiving
+/// `value_parameters`, ending in a singly lined, empty-bodied scope (`:`), interning repeated
+/// nodes and tokens through `cache` as they are built. Errs with [UnmatchedPatternError] if
+/// `identifier` or any value parameter's own identifier is not a valid one; the error's span is
+/// computed against `source`, the full text `identifier` and `value_parameters` were sliced from.
+pub(crate) fn function_green_node(
+  cache: &mut NodeCache,
+  source: &str,
+  identifier: &str,
+  value_parameters: &[ValueParameter<'_>]
+) -> Result<Arc<GreenNode>, UnmatchedPatternError> {
+  validate_identifier(source, identifier)?;
+  for value_parameter in value_parameters {
+    validate_identifier(source, value_parameter.identifier)?;
   }
+  let mut builder = GreenNodeBuilder::new(cache);
+  builder.start_node(SyntaxKind::Function);
+  builder.token(SyntaxKind::Identifier, "func");
+  builder.token(SyntaxKind::Spacing, " ");
+  builder.token(SyntaxKind::Identifier, identifier);
+  builder.token(SyntaxKind::LeftParenthesis, "(");
+  value_parameter_list(&mut builder, value_parameters);
+  builder.token(SyntaxKind::RightParenthesis, ")");
+  builder.token(SyntaxKind::Colon, ":");
+  builder.finish_node();
+  Ok(builder.finish())
+}
 
-  fn column(&self) -> u32 {}
-
-  fn next(&self) -> &Vec<Option<SpacingNode<OperationNode<impl Node>>>> {
-    &vec![Some(SpacingNode {
-      next: vec![Some(OperationNode { next: vec![None] })]
-    })]
+/// Byte offset of `text` within `source`, both assumed to share the same backing buffer — `text` is
+/// always a slice obtained by slicing `source` (directly or transitively) rather than an unrelated
+/// string. Falls back to `0` on the cases (entirely disjoint slices) where that assumption does not
+/// hold, rather than panicking, since nothing but the span reported in an error depends on it.
+fn offset_in(source: &str, text: &str) -> usize {
+  let source_start = source.as_ptr() as usize;
+  let text_start = text.as_ptr() as usize;
+  if text_start >= source_start && text_start + text.len() <= source_start + source.len() {
+    text_start - source_start
+  } else {
+    0
   }
 }
 
-/// Node that denotes the end of a declaration of a list of value parameters, started by a
-/// [ValueParametersListDeclarationStartNode].
-#[derive(Debug)]
-struct ValueParametersListDeclarationEndNode {}
+/// Errs with an [UnmatchedPatternError] unless `text` matches [IDENTIFIER_PATTERN].
+fn validate_identifier(source: &str, text: &str) -> Result<(), UnmatchedPatternError> {
+  let pattern = Regex::new(IDENTIFIER_PATTERN).unwrap();
+  if pattern.is_match(text) {
+    return Ok(());
+  }
+  let message = if text.is_empty() {
+    String::from("expected an identifier, found nothing")
+  } else {
+    format!("`{text}` is not a valid identifier: only letters and digits are allowed")
+  };
+  let start = offset_in(source, text);
+  Err(UnmatchedPatternError::new(
+    message,
+    start..start + text.len(),
+    format!("identifier matching `{pattern}`"),
+    text.to_string()
+  ))
+}
 
-impl Node for ValueParametersListDeclarationEndNode {
-  fn max_leafing() -> bool {
-    false
+/// Appends a comma-separated list of value parameters, without its delimiting parentheses, to
+/// `builder`.
+fn value_parameter_list(builder: &mut GreenNodeBuilder<'_>, value_parameters: &[ValueParameter<'_>]) {
+  builder.start_node(SyntaxKind::ValueParameterList);
+  for (index, value_parameter) in value_parameters.iter().enumerate() {
+    if index > 0 {
+      builder.token(SyntaxKind::Comma, ",");
+      builder.token(SyntaxKind::Spacing, " ");
+    }
+    builder.start_node(SyntaxKind::ValueParameter);
+    builder.token(SyntaxKind::Identifier, value_parameter.type_name);
+    builder.token(SyntaxKind::Spacing, " ");
+    builder.token(SyntaxKind::Identifier, value_parameter.identifier);
+    builder.finish_node();
   }
+  builder.finish_node();
+}
+
+/// Like [function_green_node], but keeps building past an invalid identifier instead of aborting:
+/// a mismatched `identifier` or value parameter name is pushed as a [SyntaxKind::Error] token in
+/// place of [SyntaxKind::Identifier], and its [UnmatchedPatternError] is recorded in the returned
+/// `Vec` rather than returned early, so a caller gets every mismatch the declaration contains from
+/// one pass instead of just the first.
+pub(crate) fn function_green_node_recovering(
+  cache: &mut NodeCache,
+  source: &str,
+  identifier: &str,
+  value_parameters: &[ValueParameter<'_>]
+) -> (Arc<GreenNode>, Vec<UnmatchedPatternError>) {
+  let mut errors = Vec::new();
+  let mut builder = GreenNodeBuilder::new(cache);
+  builder.start_node(SyntaxKind::Function);
+  builder.token(SyntaxKind::Identifier, "func");
+  builder.token(SyntaxKind::Spacing, " ");
+  push_identifier_recovering(&mut builder, source, identifier, &mut errors);
+  builder.token(SyntaxKind::LeftParenthesis, "(");
+  value_parameter_list_recovering(&mut builder, source, value_parameters, &mut errors);
+  builder.token(SyntaxKind::RightParenthesis, ")");
+  builder.token(SyntaxKind::Colon, ":");
+  builder.finish_node();
+  (builder.finish(), errors)
+}
 
-  fn pattern() -> Regex {
-    Regex::new(r"\)").unwrap()
+/// Appends a comma-separated list of value parameters, without its delimiting parentheses, to
+/// `builder`, recording a mismatched identifier in `errors` and continuing past it rather than
+/// aborting — see [function_green_node_recovering].
+fn value_parameter_list_recovering(
+  builder: &mut GreenNodeBuilder<'_>,
+  source: &str,
+  value_parameters: &[ValueParameter<'_>],
+  errors: &mut Vec<UnmatchedPatternError>
+) {
+  builder.start_node(SyntaxKind::ValueParameterList);
+  for (index, value_parameter) in value_parameters.iter().enumerate() {
+    if index > 0 {
+      builder.token(SyntaxKind::Comma, ",");
+      builder.token(SyntaxKind::Spacing, " ");
+    }
+    builder.start_node(SyntaxKind::ValueParameter);
+    builder.token(SyntaxKind::Identifier, value_parameter.type_name);
+    builder.token(SyntaxKind::Spacing, " ");
+    push_identifier_recovering(builder, source, value_parameter.identifier, errors);
+    builder.finish_node();
   }
+  builder.finish_node();
+}
 
-  fn next(&self) -> &Vec<Option<impl Node>> {
-    &vec![
-      None,
-      Some(vec![Some(SinglyLinedFunctionScopeDelimiterNode {})]),
-    ]
+/// Validates `text` as an identifier, pushing it as [SyntaxKind::Identifier] when it matches and as
+/// [SyntaxKind::Error] — recording the mismatch in `errors` — when it does not.
+fn push_identifier_recovering(
+  builder: &mut GreenNodeBuilder<'_>,
+  source: &str,
+  text: &str,
+  errors: &mut Vec<UnmatchedPatternError>
+) {
+  match validate_identifier(source, text) {
+    Ok(()) => builder.token(SyntaxKind::Identifier, text),
+    Err(error) => {
+      errors.push(error);
+      builder.token(SyntaxKind::Error, text);
+    }
   }
 }
 
-/// Node that denotes the start of a declaration of a list of value parameters. After it, the
-/// declaration is expected to be succeeded by the comma-separated value parameters themselves and
-/// ended with a closing parenthesis.
-#[derive(Debug)]
-struct ValueParameterListDeclarationStartNode {}
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::green::{GreenElement, GreenToken};
+  use crate::red::SyntaxNode;
 
-impl Node for ValueParameterListDeclarationStartNode {
-  fn max_leafing() -> bool {
-    false
+  #[test]
+  fn builds_function_declaration_without_value_parameters() {
+    let mut cache = NodeCache::default();
+    let root = SyntaxNode::new_root(function_green_node(&mut cache, "main", "main", &[]).unwrap());
+    assert_eq!("func main():", root.text());
   }
 
-  fn pattern() -> Regex {
-    Regex::new(r"\(").unwrap()
+  #[test]
+  fn builds_function_declaration_with_value_parameters() {
+    let mut cache = NodeCache::default();
+    let root = SyntaxNode::new_root(
+      function_green_node(&mut cache, "main", "main", &[ValueParameter {
+        type_name:  "string[]",
+        identifier: "args"
+      }])
+      .unwrap()
+    );
+    assert_eq!("func main(string[] args):", root.text());
   }
 
-  fn next(
-    &self
-  ) -> &Vec<Option<IdentifierNode<SpacingNode<IdentifierNode<ListSeparatorNode<impl Node>>>>>> {
-    &vec![Some(IdentifierNode {
-      next: vec![Some(SpacingNode {
-        next: vec![Some(IdentifierNode {
-          next: vec![
-            None,
-            Some(ListSeparatorNode {
-              next: (*self.next()).clone()
-            }),
-          ]
-        })]
-      })]
-    })]
+  #[test]
+  fn interns_repeated_value_parameter_spacing_across_calls() {
+    let mut cache = NodeCache::default();
+    let first = function_green_node(&mut cache, "a", "a", &[ValueParameter {
+      type_name:  "int",
+      identifier: "x"
+    }])
+    .unwrap();
+    let second = function_green_node(&mut cache, "b", "b", &[ValueParameter {
+      type_name:  "int",
+      identifier: "x"
+    }])
+    .unwrap();
+    assert_ne!(first.text_len(), 0);
+    assert_ne!(second.text_len(), 0);
+    assert!(Arc::ptr_eq(&value_parameter_spacing(&first), &value_parameter_spacing(&second)));
   }
-}
 
-#[derive(Debug)]
-pub(crate) struct ValueParameter<'a> {
-  /// Name of the type of the value as it was input by the user: may or may not be qualified.
-  pub(crate) type_name: &'a str,
-
-  /// Name attributed to this value parameter.
-  pub(crate) identifier: &'a str
-}
-
-/// Node of a declaration of a function.
-#[derive(Debug)]
-pub(crate) struct FunctionNode<'a> {
-  /// Identifier of the function.
-  pub(crate) name: &'a str,
-
-  /// Values expected to be passed in to the function as parameters.
-  pub(crate) valueParameters: Vec<ValueParameter<'a>>,
+  /// The spacing token between the type name and the identifier of `function`'s first value
+  /// parameter.
+  fn value_parameter_spacing(function: &GreenNode) -> Arc<GreenToken> {
+    let GreenElement::Node(list) = &function.children()[4] else {
+      panic!("expected a value parameter list");
+    };
+    let GreenElement::Node(value_parameter) = &list.children()[0] else {
+      panic!("expected a value parameter");
+    };
+    let GreenElement::Token(spacing) = &value_parameter.children()[1] else {
+      panic!("expected a spacing token");
+    };
+    spacing.clone()
+  }
 
-  /// Vertical position of this node in the AST. Corresponds to the number of the line in which it
-  /// is located in the source file.
-  column: u32,
+  #[test]
+  fn errs_on_an_invalid_function_identifier() {
+    let mut cache = NodeCache::default();
+    let source = "func 123!@#()";
+    let identifier = &source[5..11];
+    assert!(function_green_node(&mut cache, source, identifier, &[]).is_err());
+  }
 
-  /// Horizontal position of this node in the AST. Corresponds to the index of the character that
-  /// delimits the start of this node in the line of the source file in which it is located.
-  row: u32
-}
+  #[test]
+  fn errs_on_an_invalid_value_parameter_identifier() {
+    let mut cache = NodeCache::default();
+    assert!(function_green_node(&mut cache, "main", "main", &[ValueParameter {
+      type_name:  "string",
+      identifier: ""
+    }])
+    .is_err());
+  }
 
-impl<'a> Node for FunctionNode {
-  fn column(&self) -> u32 {
-    self.column
+  #[test]
+  fn reports_the_span_of_an_invalid_identifier_within_its_source() {
+    let mut cache = NodeCache::default();
+    let source = "func 123!@#()";
+    let identifier = &source[5..11];
+    let error = function_green_node(&mut cache, source, identifier, &[]).unwrap_err();
+    let report = error.report(source);
+    assert!(report.contains("column 5"));
   }
 
-  fn row(&self) -> u32 {
-    self.row
+  #[test]
+  fn recovering_keeps_building_past_an_invalid_function_identifier() {
+    let mut cache = NodeCache::default();
+    let source = "func 123!@#()";
+    let identifier = &source[5..11];
+    let (root, errors) = function_green_node_recovering(&mut cache, source, identifier, &[]);
+    assert_eq!(1, errors.len());
+    assert_eq!("func 123!@#():", SyntaxNode::new_root(root).text());
   }
 
-  fn next(&self) -> Vec<Option<IdentifierNode<ValueParameterListDeclarationStartNode>>> {
-    vec![Some(IdentifierNode::new(
-      self.name,
-      self.column,
-      self.row,
-      vec![Some(ValueParameterListDeclarationStartNode {})]
-    ))]
+  #[test]
+  fn recovering_collects_every_mismatch_from_one_pass() {
+    let mut cache = NodeCache::default();
+    let value_parameters = [ValueParameter {
+      type_name:  "string[]",
+      identifier: "2!"
+    }];
+    let (_, errors) = function_green_node_recovering(&mut cache, "1!", "1!", &value_parameters);
+    assert_eq!(2, errors.len());
   }
 }
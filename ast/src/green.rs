@@ -0,0 +1,219 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+use std::sync::Arc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::syntax_kind::SyntaxKind;
+
+/// Immutable, position-free leaf of the green tree. Unlike the former `Node` trait implementors,
+/// a token only ever stores its [SyntaxKind] and its exact source text — no `column`/`row`, no
+/// parent.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GreenToken {
+  kind: SyntaxKind,
+  #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_text", deserialize_with = "deserialize_text"))]
+  text: Arc<str>
+}
+
+#[cfg(feature = "serde")]
+fn serialize_text<S: serde::Serializer>(text: &Arc<str>, serializer: S) -> Result<S::Ok, S::Error> {
+  serializer.serialize_str(text)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_text<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Arc<str>, D::Error> {
+  String::deserialize(deserializer).map(Arc::from)
+}
+
+impl GreenToken {
+  /// Instantiates a token of the given `kind` whose source text is `text`.
+  pub(crate) fn new(kind: SyntaxKind, text: impl Into<Arc<str>>) -> Self {
+    GreenToken {
+      kind,
+      text: text.into()
+    }
+  }
+
+  pub(crate) fn kind(&self) -> SyntaxKind {
+    self.kind
+  }
+
+  pub(crate) fn text(&self) -> &str {
+    &self.text
+  }
+
+  /// Length, in UTF-8 bytes, of this token's text.
+  pub(crate) fn text_len(&self) -> u32 {
+    self.text.len() as u32
+  }
+}
+
+/// Either a [GreenNode] or a [GreenToken], sharable across identical subtrees once interned by a
+/// [NodeCache]. Deserializing requires serde's `rc` feature, since an [Arc] has to be allocated for
+/// each element read back.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) enum GreenElement {
+  Node(Arc<GreenNode>),
+  Token(Arc<GreenToken>)
+}
+
+impl GreenElement {
+  /// Length, in UTF-8 bytes, of the source text covered by this element.
+  pub(crate) fn text_len(&self) -> u32 {
+    match self {
+      GreenElement::Node(node) => node.text_len(),
+      GreenElement::Token(token) => token.text_len()
+    }
+  }
+}
+
+impl From<GreenNode> for GreenElement {
+  fn from(node: GreenNode) -> Self {
+    GreenElement::Node(Arc::new(node))
+  }
+}
+
+impl From<GreenToken> for GreenElement {
+  fn from(token: GreenToken) -> Self {
+    GreenElement::Token(Arc::new(token))
+  }
+}
+
+/// Immutable, position-free interior node of the green tree: a [SyntaxKind] tag, the total UTF-8
+/// length of the text it covers, and its children in order. Reference-counted so that identical
+/// subtrees — repeated identifiers, the `)` that closes every value parameter list, and so on —
+/// can be shared instead of cloned.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct GreenNode {
+  kind: SyntaxKind,
+  text_len: u32,
+  children: Arc<[GreenElement]>
+}
+
+/// Wire format for a [GreenNode]: just its [SyntaxKind] and ordered children. `text_len` is
+/// deliberately left out — it is always the sum of the children's lengths, so serializing it would
+/// only be redundant weight — and is recomputed by [GreenNode::new] on the way back in.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct GreenNodeRepr {
+  kind: SyntaxKind,
+  children: Vec<GreenElement>
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for GreenNode {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    GreenNodeRepr {
+      kind:     self.kind,
+      children: self.children.to_vec()
+    }
+    .serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for GreenNode {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let repr = GreenNodeRepr::deserialize(deserializer)?;
+    Ok(GreenNode::new(repr.kind, repr.children))
+  }
+}
+
+impl GreenNode {
+  /// Instantiates a node of the given `kind` from its `children`, computing its text length as the
+  /// sum of theirs.
+  pub(crate) fn new(kind: SyntaxKind, children: impl IntoIterator<Item = GreenElement>) -> Self {
+    let children: Arc<[GreenElement]> = children.into_iter().collect();
+    let text_len = children.iter().map(GreenElement::text_len).sum();
+    GreenNode {
+      kind,
+      text_len,
+      children
+    }
+  }
+
+  pub(crate) fn kind(&self) -> SyntaxKind {
+    self.kind
+  }
+
+  pub(crate) fn text_len(&self) -> u32 {
+    self.text_len
+  }
+
+  pub(crate) fn children(&self) -> &[GreenElement] {
+    &self.children
+  }
+
+  /// Returns a copy of this node with the child at `index` swapped for `replacement`, reusing
+  /// every other child's [Arc] as-is. Used by incremental reparsing to rebuild the path from an
+  /// edited token up to the root without touching untouched siblings.
+  pub(crate) fn with_replaced_child(&self, index: usize, replacement: GreenElement) -> GreenNode {
+    let mut children = self.children.to_vec();
+    children[index] = replacement;
+    GreenNode::new(self.kind, children)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn instantiates_token() {
+    let token = GreenToken::new(SyntaxKind::Identifier, "main");
+    assert_eq!(SyntaxKind::Identifier, token.kind());
+    assert_eq!("main", token.text());
+    assert_eq!(4, token.text_len());
+  }
+
+  #[test]
+  fn sums_text_length_of_children() {
+    let node = GreenNode::new(SyntaxKind::Function, [
+      GreenElement::from(GreenToken::new(SyntaxKind::Identifier, "func")),
+      GreenElement::from(GreenToken::new(SyntaxKind::Spacing, " ")),
+      GreenElement::from(GreenToken::new(SyntaxKind::Identifier, "main"))
+    ]);
+    assert_eq!(9, node.text_len());
+  }
+
+  #[test]
+  fn shares_identical_subtrees() {
+    let a = Arc::new(GreenNode::new(SyntaxKind::ValueParameter, [GreenElement::from(
+      GreenToken::new(SyntaxKind::Identifier, "args")
+    )]));
+    let b = a.clone();
+    assert!(Arc::ptr_eq(&a, &b));
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn round_trips_through_json_byte_for_byte() {
+    use crate::red::SyntaxNode;
+
+    let node = GreenNode::new(SyntaxKind::Function, [
+      GreenElement::from(GreenToken::new(SyntaxKind::Identifier, "func")),
+      GreenElement::from(GreenToken::new(SyntaxKind::Spacing, " ")),
+      GreenElement::from(GreenToken::new(SyntaxKind::Identifier, "main"))
+    ]);
+    let json = serde_json::to_string(&node).unwrap();
+    let deserialized: GreenNode = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+      SyntaxNode::new_root(Arc::new(node)).text(),
+      SyntaxNode::new_root(Arc::new(deserialized)).text()
+    );
+  }
+}
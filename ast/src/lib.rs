@@ -14,46 +14,109 @@
 //!
 //! Library by which abstract syntax trees (ASTs) are generated for Helena files.
 
-use std::{any::TypeId, sync::OnceLock};
+use crate::function::{function_green_node, function_green_node_recovering, ValueParameter};
 
-use crate::{
-  node::{NewlineNode, Node}
-};
-
-mod branch;
-mod common;
+mod error;
 mod function;
-mod node;
+mod green;
+mod line_index;
+mod node_cache;
+mod query;
+mod red;
+mod reparse;
+mod syntax_kind;
+mod syntax_text;
+
+pub use crate::error::UnmatchedPatternError;
+pub use crate::node_cache::NodeCache;
+pub use crate::query::Matcher;
+pub use crate::red::{SyntaxElement, SyntaxNode, SyntaxToken};
+pub use crate::reparse::{reparse, Edit};
+pub use crate::syntax_kind::SyntaxKind;
+pub use crate::syntax_text::SyntaxText;
 
-enum Tree<T: Node> {
-  Failed(),
-  Successful(Vec<T>)
+/// Parses `source` and returns the root of its red tree. A red node is a lazily computed cursor
+/// over a shared, position-free green tree, so identical subtrees produced while parsing — the
+/// same `:` end-of-scope token, the same repeated identifier — are reused rather than duplicated.
+/// Errs with [UnmatchedPatternError] if `source`'s function name or any of its value parameters'
+/// names is not a valid identifier.
+pub fn generate_ast(source: &str) -> Result<SyntaxNode, UnmatchedPatternError> {
+  let (identifier, value_parameters) = parse_function_signature(source);
+  let mut cache = NodeCache::default();
+  Ok(SyntaxNode::new_root(function_green_node(&mut cache, source, identifier, &value_parameters)?))
 }
 
-pub fn generate_ast(source: &str) -> Vec<impl Node> {
-  max_leafing().get()
+/// Like [generate_ast], but never bails on the first invalid identifier: every mismatch found while
+/// building the tree is recorded, and a [SyntaxKind](crate::syntax_kind::SyntaxKind)::Error token
+/// stands in the tree for the identifier it replaces, so editors can surface every diagnostic a
+/// single pass over `source` turns up instead of asking the user to fix one mistake at a time.
+pub fn generate_ast_recovering(source: &str) -> (SyntaxNode, Vec<UnmatchedPatternError>) {
+  let (identifier, value_parameters) = parse_function_signature(source);
+  let mut cache = NodeCache::default();
+  let (green, errors) = function_green_node_recovering(&mut cache, source, identifier, &value_parameters);
+  (SyntaxNode::new_root(green), errors)
 }
 
-/// Obtains the maximum amount of appearances for each type of node as an independent, top-level,
-/// leaf node in the tree. 0 in case a specific type of node cannot be a leaf, denoting that context
-/// is required for its presence to be valid.
-fn max_leafing<'a>() -> &'a [TypeId; 2] {
-  static MAX_LEAFING: OnceLock<[TypeId; 2]> = OnceLock::new();
-  MAX_LEAFING.get_or_init(|| {
-    [
-      TypeId::of::<NewlineNode<_, _>>(),
-      TypeId::of::<FunctionNode>()
-    ]
-  })
+/// Extracts the function's identifier and value parameter declarations out of a singly lined
+/// function declaration. This is a placeholder hand-written recognizer for the one grammar rule
+/// the AST currently builds; it will be superseded by a real lexer/parser pair as more of the
+/// Helena grammar lands.
+pub(crate) fn parse_function_signature(source: &str) -> (&str, Vec<ValueParameter<'_>>) {
+  let signature = source.trim_start_matches("func").trim_start();
+  let parameter_list_start = signature.find('(').unwrap_or(signature.len());
+  let identifier = &signature[..parameter_list_start];
+  let parameter_list_end = signature.rfind(')').unwrap_or(signature.len());
+  let parameter_list = signature
+    .get(parameter_list_start + 1..parameter_list_end)
+    .unwrap_or_default();
+  let value_parameters = parameter_list
+    .split(',')
+    .map(str::trim)
+    .filter(|declaration| !declaration.is_empty())
+    .filter_map(|declaration| declaration.rsplit_once(' '))
+    .map(|(type_name, identifier)| ValueParameter { type_name, identifier })
+    .collect();
+  (identifier, value_parameters)
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::{function::FunctionNode, generate_ast};
+  use crate::{generate_ast, generate_ast_recovering};
 
   #[test]
   fn generates_ast_of_singly_lined_unparemeterized_empty_bodied_function_declaration() {
-    let ast = generate_ast("func main()");
-    assert_eq!(FunctionNode, ast);
+    let ast = generate_ast("func main()").unwrap();
+    assert_eq!("func main():", ast.text());
+  }
+
+  #[test]
+  fn generates_ast_of_function_declaration_with_value_parameters() {
+    let ast = generate_ast("func main(string[] args)").unwrap();
+    assert_eq!("func main(string[] args):", ast.text());
+  }
+
+  #[test]
+  fn errs_when_the_function_identifier_is_invalid() {
+    assert!(generate_ast("func 123!@#()").is_err());
+  }
+
+  #[test]
+  fn reports_an_actionable_diagnostic_for_an_invalid_identifier() {
+    let source = "func 123!@#()";
+    let error = generate_ast(source).unwrap_err();
+    assert!(error.report(source).contains("not a valid identifier"));
+  }
+
+  #[test]
+  fn recovering_still_builds_a_tree_past_an_invalid_identifier() {
+    let (ast, errors) = generate_ast_recovering("func 123!@#()");
+    assert_eq!(1, errors.len());
+    assert_eq!("func 123!@#():", ast.text());
+  }
+
+  #[test]
+  fn recovering_collects_every_diagnostic_from_one_pass() {
+    let (_, errors) = generate_ast_recovering("func 1!(string[] 2!)");
+    assert_eq!(2, errors.len());
   }
 }
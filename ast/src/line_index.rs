@@ -0,0 +1,122 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+/// A 0-based line/column pair, expressed in both UTF-8 and UTF-16 column units — the latter is
+/// what LSP-speaking editors expect.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Position {
+  /// 0-based line number.
+  pub(crate) line: u32,
+
+  /// 0-based column, counted in UTF-8 bytes from the start of the line.
+  pub(crate) column_utf8: u32,
+
+  /// 0-based column, counted in UTF-16 code units from the start of the line.
+  pub(crate) column_utf16: u32
+}
+
+/// Converts between byte offsets and [Position]s in a piece of source text, replacing the
+/// `(row + text.len()).min(100)` arithmetic that used to stand in for real position tracking: that
+/// hack was wrong for multi-byte text and silently clamped every file to 100 lines. A `LineIndex` is
+/// built once per source by recording the byte offset of every newline, then converts offsets to
+/// positions by binary-searching that table instead of re-scanning the text. It does not convert
+/// positions back to offsets.
+pub(crate) struct LineIndex {
+  /// Byte offset of every newline in the source, in ascending order.
+  newline_offsets: Vec<u32>
+}
+
+impl LineIndex {
+  /// Scans `source` once, recording the byte offset of every newline.
+  pub(crate) fn new(source: &str) -> Self {
+    let newline_offsets = source
+      .bytes()
+      .enumerate()
+      .filter_map(|(offset, byte)| (byte == b'\n').then_some(offset as u32))
+      .collect();
+    LineIndex { newline_offsets }
+  }
+
+  /// Converts a byte `offset` into `source` into its [Position], via binary search over the
+  /// newline table.
+  pub(crate) fn offset_to_position(&self, source: &str, offset: u32) -> Position {
+    let line = match self.newline_offsets.binary_search(&offset) {
+      Ok(index) | Err(index) => index as u32
+    };
+    let line_start = self.line_start(line);
+    let line_text = &source[line_start as usize..offset as usize];
+    Position {
+      line,
+      column_utf8: offset - line_start,
+      column_utf16: line_text.chars().map(char::len_utf16).sum::<usize>() as u32
+    }
+  }
+
+  /// Byte offset at which the given 0-based `line` starts.
+  fn line_start(&self, line: u32) -> u32 {
+    if line == 0 {
+      0
+    } else {
+      self.newline_offsets[line as usize - 1] + 1
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn converts_offset_to_position_on_the_first_line() {
+    let index = LineIndex::new("func main()");
+    assert_eq!(
+      Position {
+        line:         0,
+        column_utf8:  5,
+        column_utf16: 5
+      },
+      index.offset_to_position("func main()", 5)
+    );
+  }
+
+  #[test]
+  fn converts_offset_to_position_past_a_newline() {
+    let source = "func a()\nfunc b()";
+    let index = LineIndex::new(source);
+    assert_eq!(
+      Position {
+        line:         1,
+        column_utf8:  5,
+        column_utf16: 5
+      },
+      index.offset_to_position(source, 14)
+    );
+  }
+
+  #[test]
+  fn counts_utf16_columns_separately_from_utf8_ones_on_non_ascii_lines() {
+    let source = "func café()";
+    let index = LineIndex::new(source);
+    // "é" is 2 bytes in UTF-8 but a single UTF-16 code unit, so the two column kinds diverge.
+    let position = index.offset_to_position(source, "func café(".len() as u32);
+    assert_eq!(11, position.column_utf8);
+    assert_eq!(10, position.column_utf16);
+  }
+
+  #[test]
+  fn is_not_capped_past_100_lines() {
+    let source = "\n".repeat(150) + "func main()";
+    let index = LineIndex::new(&source);
+    let position = index.offset_to_position(&source, source.len() as u32 - 1);
+    assert_eq!(150, position.line);
+  }
+}
@@ -0,0 +1,195 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::green::{GreenElement, GreenNode, GreenToken};
+use crate::syntax_kind::SyntaxKind;
+
+/// Maximum length, in bytes, of a token's text for it to be considered for interning. Tokens
+/// longer than this (an arbitrarily long identifier, say) are cheap enough to allocate once and
+/// unlikely to recur verbatim, so hashing and looking them up would waste more than it saves.
+const TOKEN_SIZE_THRESHOLD: usize = 16;
+
+/// Maximum amount of children an interior node may have for it to be considered for interning.
+/// Nodes wider than this (a value parameter list with dozens of entries) are unlikely to recur
+/// identically, so the structural hash is skipped.
+const NODE_CHILD_COUNT_THRESHOLD: usize = 8;
+
+/// Cache of the green nodes and tokens produced so far while building a tree, keyed by
+/// `(SyntaxKind, text)` for tokens and `(SyntaxKind, children hash)` for interior nodes. Small,
+/// frequently repeated pieces — single spaces, the `(`/`)`/`:` delimiters, common identifiers —
+/// are interned and shared as a single [Arc], so structural equality between two occurrences
+/// collapses to a pointer comparison and memory no longer grows linearly with repetition.
+///
+/// A node's hash only narrows down the bucket of candidates sharing it; [NodeCache::node] still
+/// compares each candidate's actual children before reusing it, so a 64-bit hash collision between
+/// two structurally different child sequences can never return the wrong cached node — it just
+/// falls through to interning both.
+#[derive(Default)]
+pub struct NodeCache {
+  tokens: HashMap<(SyntaxKind, Box<str>), Arc<GreenToken>>,
+  nodes: HashMap<(SyntaxKind, u64), Vec<Arc<GreenNode>>>
+}
+
+impl NodeCache {
+  /// Interns (or returns the already interned) token of the given `kind` and `text`.
+  pub(crate) fn token(&mut self, kind: SyntaxKind, text: &str) -> Arc<GreenToken> {
+    if text.len() > TOKEN_SIZE_THRESHOLD {
+      return Arc::new(GreenToken::new(kind, text));
+    }
+    self
+      .tokens
+      .entry((kind, Box::from(text)))
+      .or_insert_with(|| Arc::new(GreenToken::new(kind, text)))
+      .clone()
+  }
+
+  /// Interns (or returns the already interned) node of the given `kind` and `children`. Two nodes
+  /// are only ever treated as the same one if their children actually compare equal — the hash
+  /// merely picks which bucket to look in.
+  pub(crate) fn node(&mut self, kind: SyntaxKind, children: Vec<GreenElement>) -> Arc<GreenNode> {
+    if children.len() > NODE_CHILD_COUNT_THRESHOLD {
+      return Arc::new(GreenNode::new(kind, children));
+    }
+    let hash = hash_of(&children);
+    let bucket = self.nodes.entry((kind, hash)).or_default();
+    if let Some(existing) = bucket.iter().find(|node| node.children() == children.as_slice()) {
+      return existing.clone();
+    }
+    let node = Arc::new(GreenNode::new(kind, children));
+    bucket.push(node.clone());
+    node
+  }
+}
+
+fn hash_of(children: &[GreenElement]) -> u64 {
+  let mut hasher = std::collections::hash_map::DefaultHasher::new();
+  children.hash(&mut hasher);
+  hasher.finish()
+}
+
+/// Frame kept for each node currently being built, holding the children accumulated for it so far.
+struct NodeFrame {
+  kind: SyntaxKind,
+  children: Vec<GreenElement>
+}
+
+/// Builds a green tree bottom-up while interning repeated nodes and tokens through a [NodeCache],
+/// mirroring rowan's `GreenNodeBuilder`: `start_node`/`finish_node` bracket an interior node's
+/// children, `token` appends a leaf, and `finish` returns the completed root.
+pub(crate) struct GreenNodeBuilder<'cache> {
+  cache: &'cache mut NodeCache,
+  parents: Vec<NodeFrame>,
+  finished: Option<Arc<GreenNode>>
+}
+
+impl<'cache> GreenNodeBuilder<'cache> {
+  pub(crate) fn new(cache: &'cache mut NodeCache) -> Self {
+    GreenNodeBuilder {
+      cache,
+      parents: Vec::new(),
+      finished: None
+    }
+  }
+
+  /// Starts a node of the given `kind`; its children are every `token`/`start_node` call made
+  /// before the matching `finish_node`.
+  pub(crate) fn start_node(&mut self, kind: SyntaxKind) {
+    self.parents.push(NodeFrame {
+      kind,
+      children: Vec::new()
+    });
+  }
+
+  /// Appends a leaf token of the given `kind` and `text` to the node currently being built.
+  pub(crate) fn token(&mut self, kind: SyntaxKind, text: &str) {
+    let token = GreenElement::Token(self.cache.token(kind, text));
+    self.push(token);
+  }
+
+  /// Closes the node started by the innermost unmatched `start_node`, interning it and appending
+  /// it to its parent (or, if it has none, making it the finished root).
+  pub(crate) fn finish_node(&mut self) {
+    let frame = self.parents.pop().expect("finish_node without a matching start_node");
+    let node = self.cache.node(frame.kind, frame.children);
+    match self.parents.last_mut() {
+      Some(parent) => parent.children.push(GreenElement::Node(node)),
+      None => self.finished = Some(node)
+    }
+  }
+
+  fn push(&mut self, element: GreenElement) {
+    self
+      .parents
+      .last_mut()
+      .expect("token outside of any start_node")
+      .children
+      .push(element);
+  }
+
+  /// Returns the finished root. Panics if every `start_node` has not been matched by a
+  /// `finish_node`.
+  pub(crate) fn finish(self) -> Arc<GreenNode> {
+    assert!(self.parents.is_empty(), "finish called with an unmatched start_node");
+    self.finished.expect("finish called before the root node was finished")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn interns_identical_tokens() {
+    let mut cache = NodeCache::default();
+    let a = cache.token(SyntaxKind::Spacing, " ");
+    let b = cache.token(SyntaxKind::Spacing, " ");
+    assert!(Arc::ptr_eq(&a, &b));
+  }
+
+  #[test]
+  fn interns_identical_nodes() {
+    let mut cache = NodeCache::default();
+    let mut builder = GreenNodeBuilder::new(&mut cache);
+    builder.start_node(SyntaxKind::ValueParameter);
+    builder.token(SyntaxKind::Identifier, "int");
+    builder.token(SyntaxKind::Spacing, " ");
+    builder.token(SyntaxKind::Identifier, "x");
+    builder.finish_node();
+    let first = builder.finish();
+
+    let mut builder = GreenNodeBuilder::new(&mut cache);
+    builder.start_node(SyntaxKind::ValueParameter);
+    builder.token(SyntaxKind::Identifier, "int");
+    builder.token(SyntaxKind::Spacing, " ");
+    builder.token(SyntaxKind::Identifier, "x");
+    builder.finish_node();
+    let second = builder.finish();
+
+    assert!(Arc::ptr_eq(&first, &second));
+  }
+
+  #[test]
+  fn builds_nested_nodes() {
+    let mut cache = NodeCache::default();
+    let mut builder = GreenNodeBuilder::new(&mut cache);
+    builder.start_node(SyntaxKind::Function);
+    builder.token(SyntaxKind::Identifier, "func");
+    builder.start_node(SyntaxKind::ValueParameterList);
+    builder.finish_node();
+    builder.finish_node();
+    assert_eq!(2, builder.finish().children().len());
+  }
+}
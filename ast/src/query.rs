@@ -0,0 +1,172 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::red::{SyntaxElement, SyntaxNode};
+use crate::syntax_kind::SyntaxKind;
+
+/// A single step of a [SyntaxNode::query] pattern.
+#[derive(Debug)]
+pub enum Matcher {
+  /// Matches an element whose [SyntaxKind] is exactly `kind` — a leaf `Identifier` just as much as
+  /// an interior `ValueParameterList`.
+  Kind(SyntaxKind),
+
+  /// Matches an element whose text matches `pattern`.
+  Text(Regex),
+
+  /// `*`: matches any single element.
+  Any,
+
+  /// `**`: matches any number of elements — including zero — at any depth, the way `**` does in a
+  /// glob over directories.
+  AnyDepth
+}
+
+impl Matcher {
+  fn matches(&self, element: &SyntaxElement) -> bool {
+    match self {
+      Matcher::Kind(kind) => element.kind() == *kind,
+      Matcher::Text(pattern) => pattern.is_match(&element.text()),
+      Matcher::Any => true,
+      Matcher::AnyDepth => true
+    }
+  }
+}
+
+impl SyntaxNode {
+  /// Extracts every descendant element matched by `pattern` — an ordered sequence of [Matcher]s,
+  /// each consuming exactly one level of depth except [Matcher::AnyDepth], which may consume any
+  /// number of them. A depth-first search tries, at each element, to advance the pattern cursor
+  /// against every child; [Matcher::AnyDepth] spawns both a "stay" state, which tries the rest of
+  /// the pattern against the current child without descending, and a "descend" state, which keeps
+  /// looking deeper without advancing the cursor, so it behaves like `**` in a glob. Leaf tokens —
+  /// `Identifier`, `Spacing`, the delimiters — are matched just like interior nodes, since they are
+  /// walked through [SyntaxNode::children_with_tokens] rather than [SyntaxNode::children].
+  pub fn query(self: &Arc<Self>, pattern: &[Matcher]) -> impl Iterator<Item = SyntaxElement> {
+    let mut matches = Vec::new();
+    collect_among_children(self, pattern, &mut matches);
+    matches.into_iter()
+  }
+}
+
+/// Tries every child of `node` as a candidate for the next step of `pattern`.
+fn collect_among_children(node: &Arc<SyntaxNode>, pattern: &[Matcher], matches: &mut Vec<SyntaxElement>) {
+  for child in node.children_with_tokens() {
+    try_match(child, pattern, matches);
+  }
+}
+
+/// Tests whether `element` itself satisfies the next step of `pattern`, recording it (and recursing
+/// into its children for whatever of `pattern` remains, if it is a node — tokens are always leaves)
+/// when it does.
+fn try_match(element: SyntaxElement, pattern: &[Matcher], matches: &mut Vec<SyntaxElement>) {
+  let Some((first, rest)) = pattern.split_first() else {
+    return;
+  };
+  match first {
+    Matcher::AnyDepth => {
+      if rest.is_empty() {
+        matches.push(element.clone());
+      } else {
+        try_match(element.clone(), rest, matches);
+      }
+      if let SyntaxElement::Node(node) = &element {
+        collect_among_children(&Arc::new(node.clone()), pattern, matches);
+      }
+    },
+    matcher if matcher.matches(&element) => {
+      if rest.is_empty() {
+        matches.push(element.clone());
+      } else if let SyntaxElement::Node(node) = &element {
+        collect_among_children(&Arc::new(node.clone()), rest, matches);
+      }
+    },
+    _ => {}
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::generate_ast;
+
+  fn tree() -> Arc<SyntaxNode> {
+    Arc::new(generate_ast("func main(string[] args)").unwrap())
+  }
+
+  fn tree_with_two_value_parameters() -> Arc<SyntaxNode> {
+    Arc::new(generate_ast("func main(string[] args, int count)").unwrap())
+  }
+
+  #[test]
+  fn matches_by_exact_kind() {
+    let root = tree();
+    let matches: Vec<_> = root.query(&[Matcher::Kind(SyntaxKind::ValueParameterList)]).collect();
+    assert_eq!(1, matches.len());
+    assert_eq!(SyntaxKind::ValueParameterList, matches[0].kind());
+  }
+
+  #[test]
+  fn matches_by_text_pattern() {
+    let root = tree_with_two_value_parameters();
+    let matches: Vec<_> =
+      root.query(&[Matcher::AnyDepth, Matcher::Text(Regex::new(r"^int count$").unwrap())]).collect();
+    assert_eq!(1, matches.len());
+    assert_eq!("int count", matches[0].text());
+  }
+
+  #[test]
+  fn any_matches_every_direct_child_regardless_of_kind() {
+    let root = tree();
+    let matches: Vec<_> = root.query(&[Matcher::Any]).collect();
+    // "func", " ", "main", "(", the ValueParameterList node, ")", ":" — every direct child, tokens
+    // included, since a single Any/AnyDepth-less matcher never descends.
+    assert_eq!(7, matches.len());
+    assert!(matches.iter().any(|element| element.kind() == SyntaxKind::ValueParameterList));
+    assert!(matches.iter().any(|element| element.kind() == SyntaxKind::Identifier));
+  }
+
+  #[test]
+  fn any_depth_finds_a_descendant_at_any_level() {
+    let root = tree();
+    let matches: Vec<_> =
+      root.query(&[Matcher::AnyDepth, Matcher::Kind(SyntaxKind::ValueParameter)]).collect();
+    assert_eq!(1, matches.len());
+    assert_eq!("string[] args", matches[0].text());
+  }
+
+  #[test]
+  fn matches_every_identifier_token_within_a_value_parameter_list() {
+    let root = tree_with_two_value_parameters();
+    let matches: Vec<_> = root
+      .query(&[
+        Matcher::AnyDepth,
+        Matcher::Kind(SyntaxKind::ValueParameterList),
+        Matcher::AnyDepth,
+        Matcher::Kind(SyntaxKind::Identifier)
+      ])
+      .collect();
+    let texts: Vec<_> = matches.iter().map(SyntaxElement::text).collect();
+    assert_eq!(vec!["string[]", "args", "int", "count"], texts);
+  }
+
+  #[test]
+  fn does_not_match_past_a_concrete_matcher_that_fails() {
+    let root = tree();
+    let matches: Vec<_> = root.query(&[Matcher::Kind(SyntaxKind::ValueParameter)]).collect();
+    assert!(matches.is_empty());
+  }
+}
@@ -0,0 +1,258 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+use std::sync::Arc;
+
+use crate::green::{GreenElement, GreenNode, GreenToken};
+use crate::line_index::LineIndex;
+use crate::syntax_kind::SyntaxKind;
+use crate::syntax_text::SyntaxText;
+
+/// Red-layer view of a single leaf token: its shared [GreenToken] plus the absolute byte offset at
+/// which it starts, the same pairing [SyntaxNode] does for interior nodes.
+#[derive(Clone, Debug)]
+pub struct SyntaxToken {
+  green: Arc<GreenToken>,
+  offset: u32
+}
+
+impl SyntaxToken {
+  pub fn kind(&self) -> SyntaxKind {
+    self.green.kind()
+  }
+
+  pub fn text(&self) -> &str {
+    self.green.text()
+  }
+
+  pub fn text_len(&self) -> u32 {
+    self.green.text_len()
+  }
+
+  pub fn offset(&self) -> u32 {
+    self.offset
+  }
+}
+
+/// Either a [SyntaxNode] or a [SyntaxToken], yielded together by [SyntaxNode::children_with_tokens]
+/// so a caller that needs every child in source order — a leaf `Identifier` just as much as an
+/// interior `ValueParameterList` — does not have to walk the green tree itself to reach one.
+#[derive(Clone, Debug)]
+pub enum SyntaxElement {
+  Node(SyntaxNode),
+  Token(SyntaxToken)
+}
+
+impl SyntaxElement {
+  pub fn kind(&self) -> SyntaxKind {
+    match self {
+      SyntaxElement::Node(node) => node.kind(),
+      SyntaxElement::Token(token) => token.kind()
+    }
+  }
+
+  /// Concatenates (for a node) or returns (for a token) the text this element covers.
+  pub fn text(&self) -> String {
+    match self {
+      SyntaxElement::Node(node) => node.text(),
+      SyntaxElement::Token(token) => token.text().to_string()
+    }
+  }
+}
+
+/// Red — cursor — layer of the tree. Wraps a shared [GreenNode] with the two things the green
+/// layer deliberately omits: a pointer to its parent and its absolute byte offset from the start of
+/// the source. Both are computed lazily while walking the tree rather than stored on the green
+/// node itself, which is what lets identical green subtrees be reused at multiple positions.
+#[derive(Clone, Debug)]
+pub struct SyntaxNode {
+  green: Arc<GreenNode>,
+  parent: Option<Arc<SyntaxNode>>,
+  offset: u32
+}
+
+impl SyntaxNode {
+  /// Wraps `green` as the root of a red tree.
+  pub(crate) fn new_root(green: Arc<GreenNode>) -> Self {
+    SyntaxNode {
+      green,
+      parent: None,
+      offset: 0
+    }
+  }
+
+  fn new_child(green: Arc<GreenNode>, parent: Arc<SyntaxNode>, offset: u32) -> Self {
+    SyntaxNode {
+      green,
+      parent: Some(parent),
+      offset
+    }
+  }
+
+  pub fn kind(&self) -> SyntaxKind {
+    self.green.kind()
+  }
+
+  /// Length, in UTF-8 bytes, of the source text covered by this node.
+  pub fn text_len(&self) -> u32 {
+    self.green.text_len()
+  }
+
+  /// Absolute byte offset, from the start of the source, at which this node begins.
+  pub fn offset(&self) -> u32 {
+    self.offset
+  }
+
+  pub fn parent(&self) -> Option<&SyntaxNode> {
+    self.parent.as_deref()
+  }
+
+  /// Lazy, zero-copy view over the text covered by this node. Callers that only need to inspect a
+  /// span — its length, a single char, whether it contains some character, equality against a
+  /// known string — should prefer this over `text`, which always materializes a `String`.
+  pub fn syntax_text(&self) -> SyntaxText<'_> {
+    SyntaxText::new(self)
+  }
+
+  /// Concatenates the text of every token covered by this node. Prefer `syntax_text` for anything
+  /// that does not actually need an owned `String`.
+  pub fn text(&self) -> String {
+    self.syntax_text().to_string()
+  }
+
+  /// Walks up to, and returns, the root of this node's tree.
+  fn root(&self) -> &SyntaxNode {
+    match &self.parent {
+      Some(parent) => parent.root(),
+      None => self
+    }
+  }
+
+  /// 0-based line in which this node starts, derived by walking up to the root and looking this
+  /// node's absolute offset up in a [LineIndex] built from the root's text. Column/row are never
+  /// stored: they're recomputed on demand so that a green subtree shared at two different
+  /// positions reports the correct one for wherever it is currently read from.
+  pub fn row(&self) -> u32 {
+    self.position().line
+  }
+
+  /// 0-based column, in UTF-8 bytes, from the start of the line at which this node starts.
+  pub fn column(&self) -> u32 {
+    self.position().column_utf8
+  }
+
+  fn position(&self) -> crate::line_index::Position {
+    let root = self.root();
+    let text = root.text();
+    LineIndex::new(&text).offset_to_position(&text, self.offset)
+  }
+
+  /// Interior-node children of this node, each wrapped with its absolute offset computed from the
+  /// running total of its preceding siblings' lengths. Leaf tokens — `Identifier`, `Spacing`, the
+  /// delimiters — are skipped; use [SyntaxNode::children_with_tokens] to reach those too.
+  pub fn children(self: &Arc<Self>) -> impl Iterator<Item = SyntaxNode> + '_ {
+    self.children_with_tokens().filter_map(|element| match element {
+      SyntaxElement::Node(node) => Some(node),
+      SyntaxElement::Token(_) => None
+    })
+  }
+
+  /// Every child of this node, nodes and leaf tokens alike, in source order and each wrapped with
+  /// its absolute offset.
+  pub fn children_with_tokens(self: &Arc<Self>) -> impl Iterator<Item = SyntaxElement> + '_ {
+    let mut offset = self.offset;
+    self.green.children().iter().map(move |child| {
+      let child_offset = offset;
+      offset += child.text_len();
+      match child {
+        GreenElement::Node(green) => {
+          SyntaxElement::Node(SyntaxNode::new_child(green.clone(), self.clone(), child_offset))
+        }
+        GreenElement::Token(token) => SyntaxElement::Token(SyntaxToken {
+          green:  token.clone(),
+          offset: child_offset
+        })
+      }
+    })
+  }
+
+  /// The green node at the root of this node's tree, as opposed to `root`, which returns the red
+  /// node wrapping it.
+  pub(crate) fn green(&self) -> &Arc<GreenNode> {
+    &self.root().green
+  }
+
+  /// This node's own green node, as opposed to `green`, which returns the root's.
+  pub(crate) fn green_node(&self) -> &GreenNode {
+    &self.green
+  }
+
+  /// Finds the leaf token covering the given absolute byte `offset`, returning it alongside the
+  /// path of child indices, from the root down, that leads to it. `None` if `offset` is past the
+  /// end of the source.
+  pub(crate) fn token_covering(&self, offset: u32) -> Option<(Vec<usize>, SyntaxToken)> {
+    fn walk(green: &GreenNode, base_offset: u32, offset: u32, path: &mut Vec<usize>) -> Option<SyntaxToken> {
+      let mut child_offset = base_offset;
+      for (index, child) in green.children().iter().enumerate() {
+        let end = child_offset + child.text_len();
+        if offset < child_offset || offset >= end {
+          child_offset = end;
+          continue;
+        }
+        path.push(index);
+        return match child {
+          GreenElement::Token(token) => Some(SyntaxToken {
+            green:  token.clone(),
+            offset: child_offset
+          }),
+          GreenElement::Node(node) => walk(node, child_offset, offset, path)
+        };
+      }
+      None
+    }
+    let root = self.root();
+    let mut path = Vec::new();
+    walk(&root.green, root.offset, offset, &mut path).map(|token| (path, token))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use super::*;
+  use crate::green::GreenToken;
+
+  #[test]
+  fn derives_offset_of_root() {
+    let root = SyntaxNode::new_root(Arc::new(GreenNode::new(SyntaxKind::Function, [
+      GreenElement::from(GreenToken::new(SyntaxKind::Identifier, "func"))
+    ])));
+    assert_eq!(0, root.offset());
+  }
+
+  #[test]
+  fn derives_row_and_column_by_walking_up() {
+    let root = Arc::new(SyntaxNode::new_root(Arc::new(GreenNode::new(SyntaxKind::Function, [
+      GreenElement::from(GreenNode::new(SyntaxKind::Operation, [GreenElement::from(
+        GreenToken::new(SyntaxKind::Identifier, "a")
+      )])),
+      GreenElement::from(GreenToken::new(SyntaxKind::Newline, "\n")),
+      GreenElement::from(GreenNode::new(SyntaxKind::Operation, [GreenElement::from(
+        GreenToken::new(SyntaxKind::Identifier, "b")
+      )]))
+    ]))));
+    let second_operation = root.children().nth(1).unwrap();
+    assert_eq!(1, second_operation.row());
+    assert_eq!(0, second_operation.column());
+  }
+}
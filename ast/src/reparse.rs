@@ -0,0 +1,164 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::error::UnmatchedPatternError;
+use crate::function::function_green_node;
+use crate::green::{GreenElement, GreenNode, GreenToken};
+use crate::node_cache::NodeCache;
+use crate::parse_function_signature;
+use crate::red::SyntaxNode;
+use crate::syntax_kind::SyntaxKind;
+
+/// A single textual edit: the byte `range` of the old source being replaced by `replacement`.
+#[derive(Clone, Debug)]
+pub struct Edit {
+  pub range: Range<u32>,
+  pub replacement: String
+}
+
+/// Applies `edit` to `source`'s existing `root`, reusing as much of the green tree as possible
+/// instead of rebuilding it from scratch. Tries, in order: a token-level reparse, which swaps a
+/// single leaf token when the edit lexes to exactly one token of the same kind, and a full reparse
+/// of the edited source as a fallback, which runs whenever the edit crosses a token boundary. Errs
+/// with [UnmatchedPatternError] if the full-reparse fallback is taken and the edited source is no
+/// longer valid.
+pub fn reparse(
+  root: &SyntaxNode,
+  source: &str,
+  edit: &Edit,
+  cache: &mut NodeCache
+) -> Result<SyntaxNode, UnmatchedPatternError> {
+  match token_level_reparse(root, edit) {
+    Some(green) => Ok(SyntaxNode::new_root(green)),
+    None => full_reparse(source, edit, cache)
+  }
+}
+
+/// Locates the single leaf token whose span fully contains `edit`, re-lexes its spliced text, and,
+/// if it still lexes as exactly one token of the same [SyntaxKind], splices the new green token
+/// back in, reusing every untouched sibling along the way by [Arc] clone.
+fn token_level_reparse(root: &SyntaxNode, edit: &Edit) -> Option<Arc<GreenNode>> {
+  let (path, token) = root.token_covering(edit.range.start)?;
+  if edit.range.end > token.offset() + token.text_len() {
+    return None;
+  }
+  let relative_start = (edit.range.start - token.offset()) as usize;
+  let relative_end = (edit.range.end - token.offset()) as usize;
+  let mut spliced = String::with_capacity(token.text_len() as usize + edit.replacement.len());
+  spliced.push_str(&token.text()[..relative_start]);
+  spliced.push_str(&edit.replacement);
+  spliced.push_str(&token.text()[relative_end..]);
+  if !lexes_as_single_token(token.kind(), &spliced) {
+    return None;
+  }
+  let replacement = GreenElement::from(GreenToken::new(token.kind(), spliced));
+  Some(splice(root.green(), &path, replacement))
+}
+
+/// Whether `text` lexes, in its entirety, as exactly one token of `kind`: for an `Identifier`, the
+/// pattern a name is validated against while building; for the other token kinds, their one fixed
+/// spelling.
+fn lexes_as_single_token(kind: SyntaxKind, text: &str) -> bool {
+  let pattern = match kind {
+    SyntaxKind::Identifier => r"^[a-zA-Z0-9]+$",
+    SyntaxKind::Spacing => r"^ $",
+    SyntaxKind::Newline => r"^(\n|\r\n)$",
+    SyntaxKind::LeftParenthesis => r"^\($",
+    SyntaxKind::RightParenthesis => r"^\)$",
+    SyntaxKind::Colon => r"^:$",
+    SyntaxKind::Comma => r"^,$",
+    // Interior-node kinds are never a single token, and an `Error` token has no pattern to be
+    // re-lexed against; any edit inside one of them falls back to a full reparse.
+    _ => return false
+  };
+  Regex::new(pattern).unwrap().is_match(text)
+}
+
+/// Rebuilds the green tree along `path`, from the root down to the replaced element, cloning only
+/// the ancestors on that path and reusing every other child by [Arc].
+fn splice(green: &Arc<GreenNode>, path: &[usize], replacement: GreenElement) -> Arc<GreenNode> {
+  match path {
+    [] => unreachable!("splice path must contain at least the replaced element's own index"),
+    [index] => Arc::new(green.with_replaced_child(*index, replacement)),
+    [index, rest @ ..] => {
+      let child = match &green.children()[*index] {
+        GreenElement::Node(node) => node,
+        GreenElement::Token(_) => unreachable!("a token cannot have a child on the splice path")
+      };
+      let new_child = GreenElement::Node(splice(child, rest, replacement));
+      Arc::new(green.with_replaced_child(*index, new_child))
+    }
+  }
+}
+
+/// Reparses the whole of `source` with `edit` applied, ignoring the old tree entirely. The only
+/// fallback available today, since the grammar has no block boundaries narrower than the whole
+/// function declaration for a block-level reparse to stop at.
+fn full_reparse(source: &str, edit: &Edit, cache: &mut NodeCache) -> Result<SyntaxNode, UnmatchedPatternError> {
+  let mut edited = String::with_capacity(
+    source.len() - (edit.range.end - edit.range.start) as usize + edit.replacement.len()
+  );
+  edited.push_str(&source[..edit.range.start as usize]);
+  edited.push_str(&edit.replacement);
+  edited.push_str(&source[edit.range.end as usize..]);
+  let (identifier, value_parameters) = parse_function_signature(&edited);
+  Ok(SyntaxNode::new_root(function_green_node(cache, &edited, identifier, &value_parameters)?))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::generate_ast;
+
+  #[test]
+  fn reuses_the_tree_when_the_edit_stays_within_one_identifier() {
+    let mut cache = NodeCache::default();
+    let source = "func main()";
+    let root = generate_ast(source).unwrap();
+    let edit = Edit {
+      range:       7..7,
+      replacement: "2".to_string()
+    };
+    let reparsed = reparse(&root, source, &edit, &mut cache).unwrap();
+    assert_eq!("func ma2in():", reparsed.text());
+  }
+
+  #[test]
+  fn falls_back_to_a_full_reparse_when_the_edit_crosses_a_token_boundary() {
+    let mut cache = NodeCache::default();
+    let source = "func main()";
+    let root = generate_ast(source).unwrap();
+    let edit = Edit {
+      range:       4..11,
+      replacement: " greet(string name)".to_string()
+    };
+    let reparsed = reparse(&root, source, &edit, &mut cache).unwrap();
+    assert_eq!("func greet(string name):", reparsed.text());
+  }
+
+  #[test]
+  fn errs_when_the_full_reparse_fallback_produces_an_invalid_identifier() {
+    let mut cache = NodeCache::default();
+    let source = "func main()";
+    let root = generate_ast(source).unwrap();
+    let edit = Edit {
+      range:       4..11,
+      replacement: " greet!(string name)".to_string()
+    };
+    assert!(reparse(&root, source, &edit, &mut cache).is_err());
+  }
+}
@@ -0,0 +1,58 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+/// Tag carried by every node and token of the green tree, replacing the bespoke `Node`
+/// implementation that used to exist per grammar rule (`FunctionNode`, `OperationNode`,
+/// `IdentifierNode`, etc). A single enum lets the tree be position-free: a green node only needs to
+/// know what it is, not where it is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SyntaxKind {
+  /// Declaration of a function, from the `func` keyword up to the end of its scope.
+  Function,
+
+  /// A run of letters and/or digits naming a function or a value parameter. The `func` keyword and
+  /// a value parameter's type name, which may hold other characters (the `[]` of `string[]`), are
+  /// tokens of this kind as well.
+  Identifier,
+
+  /// A single space separating two tokens.
+  Spacing,
+
+  /// The platform line terminator.
+  Newline,
+
+  /// The `(` opening a value parameter list.
+  LeftParenthesis,
+
+  /// The `)` closing a value parameter list.
+  RightParenthesis,
+
+  /// The `:` that opens a singly lined function scope.
+  Colon,
+
+  /// The `,` separating two value parameters.
+  Comma,
+
+  /// A list of value parameters, delimited by [SyntaxKind::LeftParenthesis] and
+  /// [SyntaxKind::RightParenthesis].
+  ValueParameterList,
+
+  /// A single `type_name identifier` pair within a [SyntaxKind::ValueParameterList].
+  ValueParameter,
+
+  /// An operation — a statement or an expression — within a function's body.
+  Operation,
+
+  /// Text that does not match any expected pattern at the position it was found in.
+  Error
+}
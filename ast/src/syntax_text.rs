@@ -0,0 +1,175 @@
+// Copyright © 2025 Jean Silva
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//                            http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the License
+// is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing permissions and limitations under
+// the License.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::green::{GreenElement, GreenNode};
+use crate::red::SyntaxNode;
+
+/// Lazy, zero-copy view over the source text covered by a [SyntaxNode]. Rather than concatenating
+/// every covered token into an owned `String` up front, each operation walks the node's green
+/// leaves itself and stops as soon as it has its answer — inspecting a whole function signature or
+/// parameter list costs only as much as the check actually needs, not the size of the span.
+#[derive(Clone, Copy, Debug)]
+pub struct SyntaxText<'a> {
+  node: &'a SyntaxNode
+}
+
+impl<'a> SyntaxText<'a> {
+  pub(crate) fn new(node: &'a SyntaxNode) -> Self {
+    SyntaxText { node }
+  }
+
+  /// Length, in UTF-8 bytes, of the covered text.
+  pub fn len(&self) -> u32 {
+    self.node.text_len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// The character starting at byte `offset` from the start of this text, or `None` if `offset` is
+  /// out of bounds or not on a char boundary.
+  pub fn char_at(&self, offset: u32) -> Option<char> {
+    fn walk(green: &GreenNode, offset: u32) -> Option<char> {
+      let mut child_offset = 0;
+      for child in green.children() {
+        let end = child_offset + child.text_len();
+        if offset < end {
+          return match child {
+            GreenElement::Node(node) => walk(node, offset - child_offset),
+            GreenElement::Token(token) => {
+              let relative_offset = (offset - child_offset) as usize;
+              if token.text().is_char_boundary(relative_offset) {
+                token.text()[relative_offset..].chars().next()
+              } else {
+                None
+              }
+            }
+          };
+        }
+        child_offset = end;
+      }
+      None
+    }
+    if offset >= self.len() {
+      return None;
+    }
+    walk(self.node.green_node(), offset)
+  }
+
+  /// Materializes the substring covered by `range`, without allocating for the parts of this text
+  /// outside of it.
+  pub fn slice(&self, range: Range<u32>) -> String {
+    fn walk(green: &GreenNode, base_offset: u32, range: &Range<u32>, out: &mut String) {
+      let mut child_offset = base_offset;
+      for child in green.children() {
+        let end = child_offset + child.text_len();
+        if end > range.start && child_offset < range.end {
+          match child {
+            GreenElement::Node(node) => walk(node, child_offset, range, out),
+            GreenElement::Token(token) => {
+              let start = range.start.max(child_offset) - child_offset;
+              let stop = range.end.min(end) - child_offset;
+              out.push_str(&token.text()[start as usize..stop as usize]);
+            }
+          }
+        }
+        child_offset = end;
+      }
+    }
+    let range = range.start.min(self.len())..range.end.min(self.len());
+    let mut out = String::with_capacity((range.end - range.start) as usize);
+    walk(self.node.green_node(), 0, &range, &mut out);
+    out
+  }
+
+  /// Whether `needle` occurs anywhere in this text, stopping at the first token that contains it.
+  pub fn contains_char(&self, needle: char) -> bool {
+    fn walk(green: &GreenNode, needle: char) -> bool {
+      green.children().iter().any(|child| match child {
+        GreenElement::Node(node) => walk(node, needle),
+        GreenElement::Token(token) => token.text().contains(needle)
+      })
+    }
+    walk(self.node.green_node(), needle)
+  }
+}
+
+impl fmt::Display for SyntaxText<'_> {
+  /// Materializes the full covered text.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.slice(0..self.len()))
+  }
+}
+
+impl PartialEq<&str> for SyntaxText<'_> {
+  fn eq(&self, other: &&str) -> bool {
+    let other = *other;
+    fn walk<'a>(green: &GreenNode, mut remaining: &'a str) -> Option<&'a str> {
+      for child in green.children() {
+        remaining = match child {
+          GreenElement::Node(node) => walk(node, remaining)?,
+          GreenElement::Token(token) => remaining.strip_prefix(token.text())?
+        };
+      }
+      Some(remaining)
+    }
+    walk(self.node.green_node(), other) == Some("")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::generate_ast;
+
+  #[test]
+  fn reports_length_without_materializing_text() {
+    let root = generate_ast("func main()").unwrap();
+    assert_eq!(12, root.syntax_text().len());
+  }
+
+  #[test]
+  fn reads_char_at_an_offset() {
+    let root = generate_ast("func main()").unwrap();
+    assert_eq!(Some('m'), root.syntax_text().char_at(5));
+  }
+
+  #[test]
+  fn slices_a_sub_range() {
+    let root = generate_ast("func main()").unwrap();
+    assert_eq!("main", root.syntax_text().slice(5..9));
+  }
+
+  #[test]
+  fn finds_a_contained_char() {
+    let root = generate_ast("func main()").unwrap();
+    assert!(root.syntax_text().contains_char('('));
+    assert!(!root.syntax_text().contains_char('@'));
+  }
+
+  #[test]
+  fn compares_equal_to_a_str_without_allocating() {
+    let root = generate_ast("func main()").unwrap();
+    assert_eq!(root.syntax_text(), "func main():");
+  }
+
+  #[test]
+  fn returns_none_instead_of_panicking_on_a_non_char_boundary_offset() {
+    let root = generate_ast("func main(café x)").unwrap();
+    // "é" is 2 bytes wide, so the offset right after its first byte does not start a char.
+    let cafe_start = root.syntax_text().slice(0..root.syntax_text().len()).find("café").unwrap() as u32;
+    assert_eq!(None, root.syntax_text().char_at(cafe_start + 4));
+  }
+}